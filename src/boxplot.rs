@@ -0,0 +1,259 @@
+//! Box-and-whisker plots
+
+use itertools::izip;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::iter::IntoIterator;
+
+use crate::data::Matrix;
+use crate::set::{Label, Set};
+use crate::traits::{self, Data};
+use crate::{Color, Default, Display, Figure, Plot, Script};
+
+/// Fraction of the box width used to draw bars at the ends of the whiskers
+#[derive(Clone, Copy)]
+pub struct WhiskerBars(pub f64);
+
+/// Properties common to box-and-whisker plots
+///
+/// **Note** `BoxPlot` is rendered with gnuplot's `candlesticks` style, so its box width is a
+/// figure-wide setting, shared with [`Candlesticks`](crate::candlestick::Candlesticks); use
+/// [`Figure::box_width`](crate::Figure::box_width) with a
+/// [`candlestick::WidthKind`](crate::candlestick::WidthKind) to change it instead of a per-plot
+/// option.
+pub struct Properties {
+    color: Option<Color>,
+    label: Option<Cow<'static, str>>,
+    whisker_bars: Option<f64>,
+}
+
+impl Set<Color> for Properties {
+    /// Sets the color of the box and whiskers
+    fn set(&mut self, color: Color) -> &mut Properties {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl Set<Label> for Properties {
+    /// Sets the legend label
+    fn set(&mut self, label: Label) -> &mut Properties {
+        self.label = Some(label.0);
+        self
+    }
+}
+
+impl Set<WhiskerBars> for Properties {
+    /// Draws bars at the ends of the whiskers, `fraction` times as wide as the box
+    ///
+    /// **Note** No whisker bars are drawn by default
+    fn set(&mut self, fraction: WhiskerBars) -> &mut Properties {
+        self.whisker_bars = Some(fraction.0);
+        self
+    }
+}
+
+impl Default for Properties {
+    fn default() -> Properties {
+        Properties {
+            color: None,
+            label: None,
+            whisker_bars: None,
+        }
+    }
+}
+
+impl Script for Properties {
+    fn script(&self) -> String {
+        let mut script = String::from("with candlesticks ");
+
+        if let Some(fraction) = self.whisker_bars {
+            script.push_str(&format!("whiskerbars {} ", fraction));
+        }
+
+        if let Some(color) = self.color {
+            script.push_str(&format!("lc rgb '{}' ", color.display()));
+        }
+
+        if let Some(ref label) = self.label {
+            script.push_str("title '");
+            script.push_str(label);
+            script.push('\'')
+        } else {
+            script.push_str("notitle")
+        }
+
+        script
+    }
+}
+
+/// Properties of the outlier points plotted alongside a [`BoxPlot`]
+struct OutlierProperties {
+    color: Option<Color>,
+}
+
+impl Script for OutlierProperties {
+    fn script(&self) -> String {
+        let mut script = String::from("with points pt 7 ");
+
+        if let Some(color) = self.color {
+            script.push_str(&format!("lc rgb '{}' ", color.display()));
+        }
+
+        script.push_str("notitle");
+        script
+    }
+}
+
+/// A box-and-whisker plot computed from raw sample values
+///
+/// Unlike [`Candlesticks`](crate::candlestick::Candlesticks), which requires the caller to
+/// precompute the five summary values, `BoxPlot` takes the raw samples of each box and computes
+/// the Tukey five-number summary (and the outliers beyond the whisker fences) in Rust.
+pub struct BoxPlot<X, V> {
+    /// X coordinate (category position) of each box
+    pub x: X,
+    /// Raw sample values of each box
+    pub v: V,
+}
+
+impl<X, V> traits::Plot<BoxPlot<X, V>> for Figure
+where
+    V: IntoIterator,
+    V::Item: IntoIterator,
+    <V::Item as IntoIterator>::Item: Data,
+    X: IntoIterator,
+    X::Item: Data,
+{
+    type Properties = Properties;
+
+    fn plot<F>(&mut self, box_plot: BoxPlot<X, V>, configure: F) -> &mut Figure
+    where
+        F: FnOnce(&mut Properties) -> &mut Properties,
+    {
+        let (x_factor, y_factor) = crate::scale_factor(&self.axes, crate::Axes::BottomXLeftY);
+        let BoxPlot { x, v } = box_plot;
+
+        let mut props = Default::default();
+        configure(&mut props);
+
+        let mut xs = vec![];
+        let mut q1s = vec![];
+        let mut whisker_mins = vec![];
+        let mut whisker_highs = vec![];
+        let mut q3s = vec![];
+        let mut outlier_xs = vec![];
+        let mut outlier_ys = vec![];
+
+        for (x, values) in x.into_iter().zip(v) {
+            let x = x.f64();
+            let mut sorted: Vec<f64> = values.into_iter().map(Data::f64).collect();
+            sorted.sort_by(nan_last);
+
+            if sorted.is_empty() {
+                // A box with no samples has nothing to summarize, so it is skipped entirely
+                continue;
+            }
+
+            let (q1, q2, q3) = five_number_summary(&sorted);
+            let iqr = q3 - q1;
+            let lower_fence = q1 - 1.5 * iqr;
+            let upper_fence = q3 + 1.5 * iqr;
+
+            let whisker_min = sorted
+                .iter()
+                .cloned()
+                .find(|&v| v >= lower_fence)
+                .unwrap_or(q2);
+            let whisker_high = sorted
+                .iter()
+                .cloned()
+                .rev()
+                .find(|&v| v <= upper_fence)
+                .unwrap_or(q2);
+
+            for &v in &sorted {
+                if v < lower_fence || v > upper_fence {
+                    outlier_xs.push(x);
+                    outlier_ys.push(v);
+                }
+            }
+
+            xs.push(x);
+            q1s.push(q1);
+            whisker_mins.push(whisker_min);
+            whisker_highs.push(whisker_high);
+            q3s.push(q3);
+        }
+
+        let data = Matrix::new(
+            izip!(xs, q1s, whisker_mins, whisker_highs, q3s),
+            (x_factor, y_factor, y_factor, y_factor, y_factor),
+        );
+        self.plots.push(Plot::new(data, &props));
+
+        if !outlier_xs.is_empty() {
+            let outliers = Matrix::new(izip!(outlier_xs, outlier_ys), (x_factor, y_factor));
+            self.plots.push(Plot::new(
+                outliers,
+                &OutlierProperties { color: props.color },
+            ));
+        }
+
+        self
+    }
+}
+
+/// Orders `f64`s, treating NaN as sorting last
+fn nan_last(a: &f64, b: &f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(b).unwrap(),
+    }
+}
+
+/// Returns the median of a non-empty slice that is already sorted in ascending order
+///
+/// # Panics
+///
+/// Panics if `sorted` is empty
+fn median(sorted: &[f64]) -> f64 {
+    let n = sorted.len();
+    assert!(n > 0, "cannot take the median of an empty slice");
+
+    if n % 2 == 0 {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.
+    } else {
+        sorted[n / 2]
+    }
+}
+
+/// Computes the Tukey `(Q1, Q2, Q3)` summary of a non-empty slice that is already sorted in
+/// ascending order
+///
+/// A box with a single sample has no meaningful quartiles, so that one value is used for all
+/// three of `Q1`, `Q2` and `Q3`.
+///
+/// # Panics
+///
+/// Panics if `sorted` is empty
+fn five_number_summary(sorted: &[f64]) -> (f64, f64, f64) {
+    let n = sorted.len();
+    assert!(n > 0, "cannot summarize an empty slice");
+
+    if n == 1 {
+        return (sorted[0], sorted[0], sorted[0]);
+    }
+
+    let q2 = median(sorted);
+
+    let (lower, upper) = if n % 2 == 0 {
+        sorted.split_at(n / 2)
+    } else {
+        (&sorted[..n / 2], &sorted[n / 2 + 1..])
+    };
+
+    (median(lower), q2, median(upper))
+}
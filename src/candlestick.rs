@@ -5,50 +5,96 @@ use std::borrow::Cow;
 use std::iter::IntoIterator;
 
 use crate::data::Matrix;
+use crate::set::{Label, LineWidth, Set};
 use crate::traits::{self, Data};
 use crate::{Color, Default, Display, Figure, LineType, Plot, Script};
 
+/// The gnuplot `with` keyword used to render a candlestick-family plot
+#[derive(Clone, Copy)]
+enum Kind {
+    Candlesticks,
+    FinanceBars,
+}
+
+/// Width of the candlestick/finance bar box, set via [`Figure::box_width`]
+#[derive(Clone, Copy)]
+pub enum WidthKind {
+    /// An absolute width, in the units of the x axis
+    Absolute(f64),
+    /// A width relative to the width gnuplot picks automatically
+    Relative(f64),
+}
+
+impl Script for WidthKind {
+    fn script(&self) -> String {
+        match *self {
+            WidthKind::Absolute(w) => format!("set boxwidth {} absolute\n", w),
+            WidthKind::Relative(w) => format!("set boxwidth {} relative\n", w),
+        }
+    }
+}
+
+/// Fraction of the box width used to draw bars at the ends of the whiskers
+#[derive(Clone, Copy)]
+pub struct WhiskerBars(pub f64);
+
 /// Properties common to candlestick plots
 pub struct Properties {
     color: Option<Color>,
+    kind: Kind,
     label: Option<Cow<'static, str>>,
     line_type: LineType,
     linewidth: Option<f64>,
+    whisker_bars: Option<f64>,
 }
 
-impl Properties {
-    /// Sets the line color
-    pub fn color(&mut self, color: Color) -> &mut Properties {
-        self.color = Some(color);
+impl Set<LineType> for Properties {
+    /// Changes the line type
+    ///
+    /// **Note** By default `Solid` lines are used
+    fn set(&mut self, lt: LineType) -> &mut Properties {
+        self.line_type = lt;
         self
     }
+}
 
-    /// Sets the legend label
-    pub fn label<S>(&mut self, label: S) -> &mut Properties
-    where
-        S: Into<Cow<'static, str>>,
-    {
-        self.label = Some(label.into());
+impl Set<Color> for Properties {
+    /// Sets the line color
+    fn set(&mut self, color: Color) -> &mut Properties {
+        self.color = Some(color);
         self
     }
+}
 
-    /// Changes the line type
-    ///
-    /// **Note** By default `Solid` lines are used
-    pub fn line_type(&mut self, lt: LineType) -> &mut Properties {
-        self.line_type = lt;
+impl Set<Label> for Properties {
+    /// Sets the legend label
+    fn set(&mut self, label: Label) -> &mut Properties {
+        self.label = Some(label.0);
         self
     }
+}
 
+impl Set<LineWidth> for Properties {
     /// Changes the width of the line
     ///
     /// # Panics
     ///
-    /// Panics if `width` is a non-positive value
-    pub fn line_width(&mut self, lw: f64) -> &mut Properties {
-        assert!(lw > 0.);
+    /// Panics if the width is a non-positive value
+    fn set(&mut self, lw: LineWidth) -> &mut Properties {
+        assert!(lw.0 > 0.);
+
+        self.linewidth = Some(lw.0);
+        self
+    }
+}
 
-        self.linewidth = Some(lw);
+impl Set<WhiskerBars> for Properties {
+    /// Draws bars at the ends of the whiskers, `fraction` times as wide as the box
+    ///
+    /// **Note** No whisker bars are drawn by default. Only applies to [`Candlesticks`]; finance
+    /// bars have no whiskers, so this is ignored for a [`FinanceBars`] plot.
+    fn set(&mut self, fraction: WhiskerBars) -> &mut Properties {
+        self.whisker_bars = Some(fraction.0);
         self
     }
 }
@@ -57,16 +103,25 @@ impl Default for Properties {
     fn default() -> Properties {
         Properties {
             color: None,
+            kind: Kind::Candlesticks,
             label: None,
             line_type: LineType::Solid,
             linewidth: None,
+            whisker_bars: None,
         }
     }
 }
 
 impl Script for Properties {
     fn script(&self) -> String {
-        let mut script = String::from("with candlesticks ");
+        let mut script = String::from(match self.kind {
+            Kind::Candlesticks => "with candlesticks ",
+            Kind::FinanceBars => "with financebars ",
+        });
+
+        if let (Kind::Candlesticks, Some(fraction)) = (self.kind, self.whisker_bars) {
+            script.push_str(&format!("whiskerbars {} ", fraction));
+        }
 
         script.push_str(&format!("lt {} ", self.line_type.display()));
 
@@ -90,6 +145,23 @@ impl Script for Properties {
     }
 }
 
+impl Figure {
+    /// Sets the width of the candlestick/finance-bar box
+    ///
+    /// This corresponds to gnuplot's `set boxwidth` directive. Like [`configure_key`], it is a
+    /// figure-wide setting that gets emitted once, before the `plot` command, rather than a
+    /// per-plot option — gnuplot has no way to vary the box width between the candlestick-family
+    /// plots of a single figure.
+    ///
+    /// **Note** By default, gnuplot picks the box width automatically
+    ///
+    /// [`configure_key`]: struct.Figure.html#method.configure_key
+    pub fn box_width(&mut self, width: WidthKind) -> &mut Figure {
+        self.box_width = Some(width);
+        self
+    }
+}
+
 /// A candlestick consists of a box and two whiskers that extend beyond the box
 pub struct Candlesticks<X, WM, BM, BH, WH> {
     /// X coordinate of the candlestick
@@ -145,3 +217,60 @@ where
         self
     }
 }
+
+/// A traditional open/high/low/close financial bar
+pub struct FinanceBars<X, O, L, H, C> {
+    /// X coordinate of the bar
+    pub x: X,
+    /// Opening value
+    pub open: O,
+    /// Lowest value
+    pub low: L,
+    /// Highest value
+    pub high: H,
+    /// Closing value
+    pub close: C,
+}
+
+impl<X, O, L, H, C> traits::Plot<FinanceBars<X, O, L, H, C>> for Figure
+where
+    C: IntoIterator,
+    C::Item: Data,
+    H: IntoIterator,
+    H::Item: Data,
+    L: IntoIterator,
+    L::Item: Data,
+    O: IntoIterator,
+    O::Item: Data,
+    X: IntoIterator,
+    X::Item: Data,
+{
+    type Properties = Properties;
+
+    fn plot<F>(&mut self, bars: FinanceBars<X, O, L, H, C>, configure: F) -> &mut Figure
+    where
+        F: FnOnce(&mut Properties) -> &mut Properties,
+    {
+        let (x_factor, y_factor) = crate::scale_factor(&self.axes, crate::Axes::BottomXLeftY);
+        let FinanceBars {
+            x,
+            open,
+            low,
+            high,
+            close,
+        } = bars;
+
+        let mut props = Properties {
+            kind: Kind::FinanceBars,
+            ..Default::default()
+        };
+        configure(&mut props);
+
+        let data = Matrix::new(
+            izip!(x, open, low, high, close),
+            (x_factor, y_factor, y_factor, y_factor, y_factor),
+        );
+        self.plots.push(Plot::new(data, &props));
+        self
+    }
+}
@@ -0,0 +1,344 @@
+//! Error bar plots
+
+use itertools::izip;
+use std::borrow::Cow;
+use std::iter::IntoIterator;
+
+use crate::data::Matrix;
+use crate::set::{Label, LineWidth, PointSize, Set};
+use crate::traits::{self, Data};
+use crate::{Color, Default, Display, Figure, LineType, Plot, PointType, Script};
+
+/// The flavor of error bar being drawn, used to pick the right `with` keyword
+#[derive(Clone, Copy)]
+enum Kind {
+    X,
+    Y,
+    XY,
+}
+
+/// The magnitude of an error bar
+pub enum Error<D> {
+    /// The bar extends `delta` on either side of the central value
+    Symmetric(D),
+    /// The bar extends from `low` to `high`
+    Asymmetric(D, D),
+}
+
+/// The magnitude of the horizontal and vertical error bars of an [`XYErrorBars`] plot
+///
+/// Unlike pairing up two independent [`Error`] values, `XYError` only lets the `x` and `y` bars
+/// be `Symmetric` or `Asymmetric` together, so a mismatched pairing can't be constructed.
+pub enum XYError<DX, DY> {
+    /// Both bars extend `delta` above/right and below/left of the central value
+    Symmetric {
+        /// Horizontal extent of the `x` error bar
+        dx: DX,
+        /// Vertical extent of the `y` error bar
+        dy: DY,
+    },
+    /// Both bars extend from their own `low` to their own `high`
+    Asymmetric {
+        /// Low end of the `x` error bar
+        x_low: DX,
+        /// High end of the `x` error bar
+        x_high: DX,
+        /// Low end of the `y` error bar
+        y_low: DY,
+        /// High end of the `y` error bar
+        y_high: DY,
+    },
+}
+
+/// Properties common to error bar plots
+pub struct Properties {
+    color: Option<Color>,
+    kind: Kind,
+    label: Option<Cow<'static, str>>,
+    line_type: LineType,
+    linewidth: Option<f64>,
+    point_size: Option<f64>,
+    point_type: Option<PointType>,
+}
+
+impl Set<Color> for Properties {
+    /// Sets the line color
+    fn set(&mut self, color: Color) -> &mut Properties {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl Set<Label> for Properties {
+    /// Sets the legend label
+    fn set(&mut self, label: Label) -> &mut Properties {
+        self.label = Some(label.0);
+        self
+    }
+}
+
+impl Set<LineType> for Properties {
+    /// Changes the line type
+    ///
+    /// **Note** By default `Solid` lines are used
+    fn set(&mut self, lt: LineType) -> &mut Properties {
+        self.line_type = lt;
+        self
+    }
+}
+
+impl Set<LineWidth> for Properties {
+    /// Changes the width of the line
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is a non-positive value
+    fn set(&mut self, lw: LineWidth) -> &mut Properties {
+        assert!(lw.0 > 0.);
+
+        self.linewidth = Some(lw.0);
+        self
+    }
+}
+
+impl Set<PointSize> for Properties {
+    /// Changes the size of the central point marker
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is a non-positive value
+    fn set(&mut self, size: PointSize) -> &mut Properties {
+        assert!(size.0 > 0.);
+
+        self.point_size = Some(size.0);
+        self
+    }
+}
+
+impl Set<PointType> for Properties {
+    /// Changes the type of the central point marker
+    fn set(&mut self, pt: PointType) -> &mut Properties {
+        self.point_type = Some(pt);
+        self
+    }
+}
+
+impl Default for Properties {
+    fn default() -> Properties {
+        Properties {
+            color: None,
+            kind: Kind::Y,
+            label: None,
+            line_type: LineType::Solid,
+            linewidth: None,
+            point_size: None,
+            point_type: None,
+        }
+    }
+}
+
+impl Script for Properties {
+    fn script(&self) -> String {
+        let mut script = match self.kind {
+            Kind::X => String::from("with xerrorbars "),
+            Kind::Y => String::from("with yerrorbars "),
+            Kind::XY => String::from("with xyerrorbars "),
+        };
+
+        script.push_str(&format!("lt {} ", self.line_type.display()));
+
+        if let Some(lw) = self.linewidth {
+            script.push_str(&format!("lw {} ", lw))
+        }
+
+        if let Some(pt) = self.point_type {
+            script.push_str(&format!("pt {} ", pt.display()))
+        }
+
+        if let Some(ps) = self.point_size {
+            script.push_str(&format!("ps {} ", ps))
+        }
+
+        if let Some(color) = self.color {
+            script.push_str(&format!("lc rgb '{}' ", color.display()));
+        }
+
+        if let Some(ref label) = self.label {
+            script.push_str("title '");
+            script.push_str(label);
+            script.push('\'')
+        } else {
+            script.push_str("notitle")
+        }
+
+        script
+    }
+}
+
+/// Error bars drawn horizontally, around each `x` value
+pub struct XErrorBars<X, Y, D> {
+    /// X coordinate of the central point
+    pub x: X,
+    /// Y coordinate of the central point
+    pub y: Y,
+    /// Horizontal extent of the error bar
+    pub error: Error<D>,
+}
+
+impl<X, Y, D> traits::Plot<XErrorBars<X, Y, D>> for Figure
+where
+    D: IntoIterator,
+    D::Item: Data,
+    X: IntoIterator,
+    X::Item: Data,
+    Y: IntoIterator,
+    Y::Item: Data,
+{
+    type Properties = Properties;
+
+    fn plot<F>(&mut self, eb: XErrorBars<X, Y, D>, configure: F) -> &mut Figure
+    where
+        F: FnOnce(&mut Properties) -> &mut Properties,
+    {
+        let (x_factor, y_factor) = crate::scale_factor(&self.axes, crate::Axes::BottomXLeftY);
+        let XErrorBars { x, y, error } = eb;
+
+        let mut props = Properties {
+            kind: Kind::X,
+            ..Default::default()
+        };
+        configure(&mut props);
+
+        match error {
+            Error::Symmetric(delta) => {
+                let data = Matrix::new(izip!(x, y, delta), (x_factor, y_factor, x_factor));
+                self.plots.push(Plot::new(data, &props));
+            }
+            Error::Asymmetric(low, high) => {
+                let data = Matrix::new(
+                    izip!(x, y, low, high),
+                    (x_factor, y_factor, x_factor, x_factor),
+                );
+                self.plots.push(Plot::new(data, &props));
+            }
+        }
+
+        self
+    }
+}
+
+/// Error bars drawn vertically, around each `y` value
+pub struct YErrorBars<X, Y, D> {
+    /// X coordinate of the central point
+    pub x: X,
+    /// Y coordinate of the central point
+    pub y: Y,
+    /// Vertical extent of the error bar
+    pub error: Error<D>,
+}
+
+impl<X, Y, D> traits::Plot<YErrorBars<X, Y, D>> for Figure
+where
+    D: IntoIterator,
+    D::Item: Data,
+    X: IntoIterator,
+    X::Item: Data,
+    Y: IntoIterator,
+    Y::Item: Data,
+{
+    type Properties = Properties;
+
+    fn plot<F>(&mut self, eb: YErrorBars<X, Y, D>, configure: F) -> &mut Figure
+    where
+        F: FnOnce(&mut Properties) -> &mut Properties,
+    {
+        let (x_factor, y_factor) = crate::scale_factor(&self.axes, crate::Axes::BottomXLeftY);
+        let YErrorBars { x, y, error } = eb;
+
+        let mut props = Properties {
+            kind: Kind::Y,
+            ..Default::default()
+        };
+        configure(&mut props);
+
+        match error {
+            Error::Symmetric(delta) => {
+                let data = Matrix::new(izip!(x, y, delta), (x_factor, y_factor, y_factor));
+                self.plots.push(Plot::new(data, &props));
+            }
+            Error::Asymmetric(low, high) => {
+                let data = Matrix::new(
+                    izip!(x, y, low, high),
+                    (x_factor, y_factor, y_factor, y_factor),
+                );
+                self.plots.push(Plot::new(data, &props));
+            }
+        }
+
+        self
+    }
+}
+
+/// Error bars drawn both horizontally (around `x`) and vertically (around `y`)
+pub struct XYErrorBars<X, Y, DX, DY> {
+    /// X coordinate of the central point
+    pub x: X,
+    /// Y coordinate of the central point
+    pub y: Y,
+    /// Horizontal and vertical extent of the error bars
+    pub error: XYError<DX, DY>,
+}
+
+impl<X, Y, DX, DY> traits::Plot<XYErrorBars<X, Y, DX, DY>> for Figure
+where
+    DX: IntoIterator,
+    DX::Item: Data,
+    DY: IntoIterator,
+    DY::Item: Data,
+    X: IntoIterator,
+    X::Item: Data,
+    Y: IntoIterator,
+    Y::Item: Data,
+{
+    type Properties = Properties;
+
+    fn plot<F>(&mut self, eb: XYErrorBars<X, Y, DX, DY>, configure: F) -> &mut Figure
+    where
+        F: FnOnce(&mut Properties) -> &mut Properties,
+    {
+        let (x_factor, y_factor) = crate::scale_factor(&self.axes, crate::Axes::BottomXLeftY);
+        let XYErrorBars { x, y, error } = eb;
+
+        let mut props = Properties {
+            kind: Kind::XY,
+            ..Default::default()
+        };
+        configure(&mut props);
+
+        match error {
+            XYError::Symmetric { dx, dy } => {
+                let data = Matrix::new(
+                    izip!(x, y, dx, dy),
+                    (x_factor, y_factor, x_factor, y_factor),
+                );
+                self.plots.push(Plot::new(data, &props));
+            }
+            XYError::Asymmetric {
+                x_low,
+                x_high,
+                y_low,
+                y_high,
+            } => {
+                let data = Matrix::new(
+                    izip!(x, y, x_low, x_high, y_low, y_high),
+                    (
+                        x_factor, y_factor, x_factor, x_factor, y_factor, y_factor,
+                    ),
+                );
+                self.plots.push(Plot::new(data, &props));
+            }
+        }
+
+        self
+    }
+}
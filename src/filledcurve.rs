@@ -4,50 +4,88 @@ use std::borrow::Cow;
 use std::iter::IntoIterator;
 
 use data::Matrix;
+use set::{Label, Opacity, Set};
 use traits::{self, Data};
 use {Axes, Color, Default, Display, Figure, Plot, Script};
 
 /// Properties common to filled curve plots
 pub struct Properties {
     axes: Option<Axes>,
+    border: Option<Option<Color>>,
     color: Option<Color>,
+    fill_region: Option<FillRegion>,
     label: Option<Cow<'static, str>>,
     opacity: Option<f64>,
 }
 
-impl Properties {
+impl Set<Axes> for Properties {
     /// Select axes to plot against
     ///
     /// **Note** By default, the `BottomXLeftY` axes are used
-    pub fn axes(&mut self, axes: Axes) -> &mut Properties {
+    fn set(&mut self, axes: Axes) -> &mut Properties {
         self.axes = Some(axes);
         self
     }
+}
+
+impl Set<Border> for Properties {
+    /// Draws the border of the filled region
+    ///
+    /// Pass `Border(None)` to draw the border using the default line color, or
+    /// `Border(Some(color))` to pick a specific color for it.
+    ///
+    /// **Note** By default, no border is drawn
+    fn set(&mut self, border: Border) -> &mut Properties {
+        self.border = Some(border.0);
+        self
+    }
+}
 
+impl Set<Color> for Properties {
     /// Sets the fill color
-    pub fn color(&mut self, color: Color) -> &mut Properties {
+    fn set(&mut self, color: Color) -> &mut Properties {
         self.color = Some(color);
         self
     }
+}
+
+impl Set<FillRegion> for Properties {
+    /// Selects which region of the plane is filled, relative to the single curve of a
+    /// [`FilledCurveToReference`] plot
+    ///
+    /// **Note** Only applies to [`FilledCurveToReference`]; a [`FilledCurve`] always fills the
+    /// region between its two curves
+    ///
+    /// # Panics
+    ///
+    /// Setting this and then plotting a [`FilledCurve`] panics, since [`FillRegion`] has no
+    /// meaning for a two-curve fill
+    fn set(&mut self, region: FillRegion) -> &mut Properties {
+        self.fill_region = Some(region);
+        self
+    }
+}
 
+impl Set<Label> for Properties {
     /// Sets the legend label
-    pub fn label<S>(&mut self, label: S) -> &mut Properties
-    where
-        S: Into<Cow<'static, str>>,
-    {
-        self.label = Some(label.into());
+    fn set(&mut self, label: Label) -> &mut Properties {
+        self.label = Some(label.0);
         self
     }
+}
 
+impl Set<Opacity> for Properties {
     /// Changes the opacity of the fill color
     ///
     /// **Note** By default, the fill color is totally opaque (`opacity = 1.0`)
     ///
     /// # Panics
     ///
-    /// Panics if `opacity` is outside the range `[0, 1]`
-    pub fn opacity(&mut self, opacity: f64) -> &mut Properties {
-        self.opacity = Some(opacity);
+    /// Panics if the opacity is outside the range `[0, 1]`
+    fn set(&mut self, opacity: Opacity) -> &mut Properties {
+        assert!(opacity.0 >= 0. && opacity.0 <= 1.);
+
+        self.opacity = Some(opacity.0);
         self
     }
 }
@@ -56,7 +94,9 @@ impl Default for Properties {
     fn default() -> Properties {
         Properties {
             axes: None,
+            border: None,
             color: None,
+            fill_region: None,
             label: None,
             opacity: None,
         }
@@ -72,14 +112,26 @@ impl Script for Properties {
         };
         script.push_str("with filledcurves ");
 
+        match self.fill_region {
+            None => {}
+            Some(FillRegion::Above) => script.push_str("above "),
+            Some(FillRegion::Below) => script.push_str("below "),
+            Some(FillRegion::ToValue(y)) => script.push_str(&format!("y1={} ", y)),
+        }
+
         script.push_str("fillstyle ");
 
         if let Some(opacity) = self.opacity {
             script.push_str(&format!("solid {} ", opacity))
         }
 
-        // TODO border shoulde be configurable
-        script.push_str("noborder ");
+        match self.border {
+            None => script.push_str("noborder "),
+            Some(None) => script.push_str("border "),
+            Some(Some(color)) => {
+                script.push_str(&format!("border lc rgb '{}' ", color.display()))
+            }
+        }
 
         if let Some(color) = self.color {
             script.push_str(&format!("lc rgb '{}' ", color.display()));
@@ -97,9 +149,33 @@ impl Script for Properties {
     }
 }
 
+/// The border of the filled region, see [`Set<Border>`](struct.Properties.html)
+#[derive(Clone, Copy)]
+pub struct Border(pub Option<Color>);
+
+/// Region of the plane that gets filled in a [`FilledCurveToReference`] plot, set via
+/// `Set<FillRegion>`
+#[derive(Clone, Copy)]
+pub enum FillRegion {
+    /// Fills the area above the curve
+    Above,
+    /// Fills the area below the curve
+    Below,
+    /// Fills the area between the curve and the constant value `y`
+    ToValue(f64),
+}
+
 /// Fills the area between two curves
+///
+/// To fill the area between a single curve and a reference (see [`FillRegion::Above`],
+/// [`FillRegion::Below`] and [`FillRegion::ToValue`]) use [`FilledCurveToReference`] instead.
+///
+/// # Panics
+///
+/// Plotting a `FilledCurve` after setting a [`FillRegion`] panics, since `FillRegion` only
+/// applies to [`FilledCurveToReference`]
 pub struct FilledCurve<X, Y1, Y2> {
-    /// X coordinate of the data points of both curves
+    /// X coordinate of the data points of the curves
     pub x: X,
     /// Y coordinate of the data points of the first curve
     pub y1: Y1,
@@ -127,6 +203,11 @@ where
         let mut props = Default::default();
         configure(&mut props);
 
+        assert!(
+            props.fill_region.is_none(),
+            "`FillRegion` only applies to `FilledCurveToReference`, not `FilledCurve`"
+        );
+
         let (x_factor, y_factor) =
             ::scale_factor(&self.axes, props.axes.unwrap_or(::Axes::BottomXLeftY));
 
@@ -135,3 +216,41 @@ where
         self
     }
 }
+
+/// Fills the area between a single curve and a reference, see [`FillRegion::Above`],
+/// [`FillRegion::Below`] and [`FillRegion::ToValue`]
+///
+/// To fill the area between two curves use [`FilledCurve`] instead.
+pub struct FilledCurveToReference<X, Y> {
+    /// X coordinate of the data points of the curve
+    pub x: X,
+    /// Y coordinate of the data points of the curve
+    pub y: Y,
+}
+
+impl<X, Y> traits::Plot<FilledCurveToReference<X, Y>> for Figure
+where
+    X: IntoIterator,
+    X::Item: Data,
+    Y: IntoIterator,
+    Y::Item: Data,
+{
+    type Properties = Properties;
+
+    fn plot<F>(&mut self, fc: FilledCurveToReference<X, Y>, configure: F) -> &mut Figure
+    where
+        F: FnOnce(&mut Properties) -> &mut Properties,
+    {
+        let FilledCurveToReference { x, y } = fc;
+
+        let mut props = Default::default();
+        configure(&mut props);
+
+        let (x_factor, y_factor) =
+            ::scale_factor(&self.axes, props.axes.unwrap_or(::Axes::BottomXLeftY));
+
+        let data = Matrix::new(izip!(x, y), (x_factor, y_factor));
+        self.plots.push(Plot::new(data, &props));
+        self
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::borrow::Cow;
 
+use crate::set::{Label, Set};
 use crate::{Default, Display, Script};
 
 /// Properties of the key.
@@ -14,8 +15,12 @@ pub struct KeyProperties {
     boxed: bool,
     hidden: bool,
     justification: Option<Justification>,
+    max_columns: Option<usize>,
+    max_rows: Option<usize>,
     order: Option<Order>,
     position: Option<Position>,
+    sample_length: Option<f64>,
+    spacing: Option<f64>,
     stacked: Option<Stacked>,
     title: Option<Cow<'static, str>>,
 }
@@ -26,8 +31,12 @@ impl Default for KeyProperties {
             boxed: false,
             hidden: false,
             justification: None,
+            max_columns: None,
+            max_rows: None,
             order: None,
             position: None,
+            sample_length: None,
+            spacing: None,
             stacked: None,
             title: None,
         }
@@ -56,43 +65,86 @@ impl KeyProperties {
         self.boxed = boxed;
         self
     }
+}
 
+impl Set<Justification> for KeyProperties {
     /// Changes the justification of the text of each entry
     ///
     /// **Note** The text is `RightJustified` by default
-    pub fn justification(&mut self, justification: Justification) -> &mut KeyProperties {
+    fn set(&mut self, justification: Justification) -> &mut KeyProperties {
         self.justification = Some(justification);
         self
     }
+}
 
+impl Set<Order> for KeyProperties {
     /// How to order each entry
     ///
     /// **Note** The default order is `TextSample`
-    pub fn order(&mut self, order: Order) -> &mut KeyProperties {
+    fn set(&mut self, order: Order) -> &mut KeyProperties {
         self.order = Some(order);
         self
     }
+}
 
+impl Set<Position> for KeyProperties {
     /// Selects where to place the key
     ///
     /// **Note** By default, the key is placed `Inside(Vertical::Top, Horizontal::Right)`
-    pub fn position(&mut self, position: Position) -> &mut KeyProperties {
+    fn set(&mut self, position: Position) -> &mut KeyProperties {
         self.position = Some(position);
         self
     }
+}
 
+impl Set<Stacked> for KeyProperties {
     /// Changes how the entries of the key are stacked
-    pub fn stacked(&mut self, stacked: Stacked) -> &mut KeyProperties {
+    fn set(&mut self, stacked: Stacked) -> &mut KeyProperties {
         self.stacked = Some(stacked);
         self
     }
+}
+
+impl Set<Label> for KeyProperties {
+    /// Sets the title
+    fn set(&mut self, title: Label) -> &mut KeyProperties {
+        self.title = Some(title.0);
+        self
+    }
+}
+
+impl Set<MaxColumns> for KeyProperties {
+    /// Wraps the entries into at most this many columns
+    ///
+    /// **Note** By default, gnuplot picks the number of columns automatically
+    fn set(&mut self, max_columns: MaxColumns) -> &mut KeyProperties {
+        self.max_columns = Some(max_columns.0);
+        self
+    }
+}
 
-    /// Set the title
-    pub fn title<S>(&mut self, title: S) -> &mut KeyProperties
-    where
-        S: Into<Cow<'static, str>>,
-    {
-        self.title = Some(title.into());
+impl Set<MaxRows> for KeyProperties {
+    /// Wraps the entries into at most this many rows
+    ///
+    /// **Note** By default, gnuplot picks the number of rows automatically
+    fn set(&mut self, max_rows: MaxRows) -> &mut KeyProperties {
+        self.max_rows = Some(max_rows.0);
+        self
+    }
+}
+
+impl Set<SampleLength> for KeyProperties {
+    /// Changes the length of the line sample that is drawn next to each entry
+    fn set(&mut self, sample_length: SampleLength) -> &mut KeyProperties {
+        self.sample_length = Some(sample_length.0);
+        self
+    }
+}
+
+impl Set<Spacing> for KeyProperties {
+    /// Changes the vertical spacing between entries, as a multiple of the character height
+    fn set(&mut self, spacing: Spacing) -> &mut KeyProperties {
+        self.spacing = Some(spacing.0);
         self
     }
 }
@@ -113,6 +165,10 @@ impl Script for KeyProperties {
             Some(Position::Outside(v, h)) => {
                 script.push_str(&format!("outside {} {} ", v.display(), h.display()))
             }
+            Some(Position::At(x, y, coord)) => {
+                let coord = coord.display();
+                script.push_str(&format!("at {} {}, {} {} ", coord, x, coord, y))
+            }
         }
 
         if let Some(stacked) = self.stacked {
@@ -120,6 +176,22 @@ impl Script for KeyProperties {
             script.push(' ');
         }
 
+        if let Some(max_rows) = self.max_rows {
+            script.push_str(&format!("maxrows {} ", max_rows));
+        }
+
+        if let Some(max_columns) = self.max_columns {
+            script.push_str(&format!("maxcols {} ", max_columns));
+        }
+
+        if let Some(sample_length) = self.sample_length {
+            script.push_str(&format!("samplen {} ", sample_length));
+        }
+
+        if let Some(spacing) = self.spacing {
+            script.push_str(&format!("spacing {} ", spacing));
+        }
+
         if let Some(justification) = self.justification {
             script.push_str(justification.display());
             script.push(' ');
@@ -172,15 +244,65 @@ pub enum Order {
 }
 
 /// Position of the key
-// TODO XY position
 #[derive(Clone, Copy)]
 pub enum Position {
     /// Inside the area surrounded by the four (BottomX, TopX, LeftY and RightY) axes
     Inside(Vertical, Horizontal),
     /// Outside of that area
     Outside(Vertical, Horizontal),
+    /// At an exact `(x, y)` coordinate, expressed in the given `CoordSystem`
+    At(f64, f64, CoordSystem),
+}
+
+/// Coordinate system used to interpret the `(x, y)` pair of [`Position::At`]
+#[derive(Clone, Copy)]
+pub enum CoordSystem {
+    /// Relative to the graph, where `(0, 0)` is the bottom left corner and `(1, 1)` is the top
+    /// right corner
+    Graph,
+    /// Relative to the whole screen (the output canvas), same corners as `Graph`
+    Screen,
+    /// In the units of the first (bottom/left) axes
+    FirstAxes,
+    /// In the units of the second (top/right) axes
+    SecondAxes,
+}
+
+impl Display<&'static str> for CoordSystem {
+    fn display(&self) -> &'static str {
+        match *self {
+            CoordSystem::Graph => "graph",
+            CoordSystem::Screen => "screen",
+            CoordSystem::FirstAxes => "first",
+            CoordSystem::SecondAxes => "second",
+        }
+    }
 }
 
+/// Maximum number of columns the key entries are wrapped into, see [`Set<MaxColumns>`]
+///
+/// [`Set<MaxColumns>`]: struct.KeyProperties.html
+#[derive(Clone, Copy)]
+pub struct MaxColumns(pub usize);
+
+/// Maximum number of rows the key entries are wrapped into, see [`Set<MaxRows>`]
+///
+/// [`Set<MaxRows>`]: struct.KeyProperties.html
+#[derive(Clone, Copy)]
+pub struct MaxRows(pub usize);
+
+/// Length of the line sample drawn next to each key entry, see [`Set<SampleLength>`]
+///
+/// [`Set<SampleLength>`]: struct.KeyProperties.html
+#[derive(Clone, Copy)]
+pub struct SampleLength(pub f64);
+
+/// Vertical spacing between key entries, see [`Set<Spacing>`]
+///
+/// [`Set<Spacing>`]: struct.KeyProperties.html
+#[derive(Clone, Copy)]
+pub struct Spacing(pub f64);
+
 /// How the entries of the key are stacked
 #[allow(missing_docs)]
 #[derive(Clone, Copy)]
@@ -0,0 +1,52 @@
+//! A generic, composable way to configure the `Properties` of any plot type
+
+use std::borrow::Cow;
+
+/// Sets a configuration value on `self`, returning `self` to allow chaining
+///
+/// Every plot's `Properties` type implements `Set<T>` for each configuration value `T` it
+/// accepts, so a caller can write the same `.set(...)` call regardless of which plot type is
+/// being configured, e.g. `.set(Color::Red).set(Label::from("foo")).set(LineWidth(2.))`.
+pub trait Set<T> {
+    /// Sets `value` on `self`
+    fn set(&mut self, value: T) -> &mut Self;
+}
+
+/// The legend label of a plot
+///
+/// Construct one with `Label::from(title)`, where `title` is anything that converts into a
+/// `Cow<'static, str>` (e.g. a `&'static str` or an owned `String`).
+pub struct Label(pub Cow<'static, str>);
+
+impl<S> From<S> for Label
+where
+    S: Into<Cow<'static, str>>,
+{
+    fn from(label: S) -> Label {
+        Label(label.into())
+    }
+}
+
+/// The width of a line
+///
+/// # Panics
+///
+/// Setting a non-positive `LineWidth` on a `Properties` panics
+#[derive(Clone, Copy)]
+pub struct LineWidth(pub f64);
+
+/// The size of a point marker
+///
+/// # Panics
+///
+/// Setting a non-positive `PointSize` on a `Properties` panics
+#[derive(Clone, Copy)]
+pub struct PointSize(pub f64);
+
+/// The opacity of a fill color, in the range `[0, 1]`
+///
+/// # Panics
+///
+/// Setting an `Opacity` outside `[0, 1]` on a `Properties` panics
+#[derive(Clone, Copy)]
+pub struct Opacity(pub f64);